@@ -7,10 +7,15 @@
 
 use core::fmt;
 
+use hashes::{sha256t_hash_newtype, Hash as _, HashEngine as _};
+use hex::FromHex;
 use internals::write_err;
 use io::Write;
+use k256::elliptic_curve::ops::Reduce;
+use k256::{AffinePoint, ProjectivePoint, Scalar};
 
-use crate::sighash::{InvalidSighashTypeError, TapSighashType};
+use crate::key::{Keypair, XOnlyPublicKey};
+use crate::sighash::{InvalidSighashTypeError, TapSighash, TapSighashType};
 use crate::taproot::serialized_signature::{self, SerializedSignature};
 use crate::{prelude::*, CryptoError};
 
@@ -45,8 +50,28 @@ impl PartialOrd for Signature {
 }
 
 impl Signature {
+    /// Signs `msg` with `keypair`, tagging the result with `sighash_type`.
+    ///
+    /// This keeps the sighash-type coupling that this type exists for in one place: callers get
+    /// back a `Signature` that already knows how it should be serialized, instead of signing with
+    /// `k256` directly and having to remember to attach the sighash type afterwards.
+    pub fn sign(msg: TapSighash, keypair: &Keypair, sighash_type: TapSighashType) -> Signature {
+        let signature = keypair.sign_schnorr(msg.as_ref());
+        Signature { signature, sighash_type }
+    }
+
+    /// Verifies that this is a valid BIP340 signature over `msg` by `pubkey`.
+    ///
+    /// This does not check `self.sighash_type`; which sighash types are acceptable is a decision
+    /// for the caller.
+    pub fn verify(&self, msg: TapSighash, pubkey: &XOnlyPublicKey) -> Result<(), CryptoError> {
+        pubkey
+            .verify_schnorr(msg.as_ref(), &self.signature)
+            .map_err(|_| CryptoError::InvalidSignature)
+    }
+
     /// Deserialize from slice
-    pub fn from_slice(sl: &[u8]) -> Result<Self, SigFromSliceError> {
+    pub fn from_slice(sl: &[u8]) -> Result<Self, FromSliceError> {
         match sl.len() {
             64 => {
                 // default type
@@ -59,7 +84,8 @@ impl Signature {
             }
             65 => {
                 let (sighash_type, signature) = sl.split_last().expect("Slice len checked == 65");
-                let sighash_type = TapSighashType::from_consensus_u8(*sighash_type)?;
+                let sighash_type = TapSighashType::from_consensus_u8(*sighash_type)
+                    .map_err(SigFromSliceError::from)?;
                 let signature = k256::schnorr::Signature::try_from(signature)
                     .map_err(|_| SigFromSliceError::Secp256k1(CryptoError::InvalidSignature))?;
                 Ok(Signature {
@@ -67,7 +93,7 @@ impl Signature {
                     sighash_type,
                 })
             }
-            len => Err(SigFromSliceError::InvalidSignatureSize(len)),
+            len => Err(InvalidSignatureSizeError(len).into()),
         }
     }
 
@@ -110,7 +136,246 @@ impl Signature {
     }
 }
 
-/// An error constructing a [`taproot::Signature`] from a byte slice.
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.serialize(), f)
+    }
+}
+
+impl fmt::LowerHex for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.serialize(), f)
+    }
+}
+
+impl core::str::FromStr for Signature {
+    type Err = SigParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Vec::<u8>::from_hex(s)?;
+        Ok(Signature::from_slice(&bytes)?)
+    }
+}
+
+/// An error parsing a [`taproot::Signature`] from its hex string representation.
+///
+/// [`taproot::Signature`]: crate::crypto::taproot::Signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SigParseError {
+    /// The string was not valid hex.
+    Hex(hex::HexToBytesError),
+    /// The decoded bytes were not a valid taproot signature.
+    Sig(FromSliceError),
+}
+
+impl fmt::Display for SigParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SigParseError::*;
+
+        match *self {
+            Hex(ref e) => write_err!(f, "hex"; e),
+            Sig(ref e) => write_err!(f, "signature"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SigParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SigParseError::*;
+
+        match *self {
+            Hex(ref e) => Some(e),
+            Sig(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<hex::HexToBytesError> for SigParseError {
+    fn from(e: hex::HexToBytesError) -> Self {
+        Self::Hex(e)
+    }
+}
+
+impl From<FromSliceError> for SigParseError {
+    fn from(e: FromSliceError) -> Self {
+        Self::Sig(e)
+    }
+}
+
+/// Verifies many taproot signatures at once using the BIP340 batch verification equation.
+///
+/// This is substantially faster than calling [`Signature::verify`] on each `(signature, message,
+/// public key)` tuple individually, which makes it the right tool for bulk validation workloads
+/// such as checking every signature in a block or a PSBT. The tradeoff is diagnostic power: a
+/// failed batch only tells you *that* some signature was invalid, not *which* one. Callers that
+/// need to localize a failure should fall back to [`Signature::verify`] per item.
+///
+/// For the `i`-th tuple with signature parsed into `(R_i, s_i)`, public key `P_i` and message
+/// `m_i`, this draws pseudorandom scalars `a_1 = 1` and `a_2..a_u` from a deterministic stream
+/// seeded by hashing every input, then checks the single equation
+/// `(Σ a_i·s_i)·G == Σ a_i·R_i + Σ (a_i·e_i)·P_i`, where `e_i` is the usual BIP340 challenge.
+/// Each `P_i` is lifted from its x-only encoding to the point with even Y, and each `R_i` must be
+/// a valid x-coordinate lifting to even Y; any failed lift or out-of-range `s_i` fails the batch.
+pub fn batch_verify<I>(items: I) -> Result<(), BatchVerifyError>
+where
+    I: IntoIterator<Item = (Signature, TapSighash, XOnlyPublicKey)>,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    // Parse every (R_i, s_i, P_i, e_i) up front and feed the raw bytes into the coefficient
+    // seed; any malformed input fails the whole batch rather than being silently skipped.
+    let mut parsed = Vec::with_capacity(items.len());
+    let mut seed_engine = BatchVerifyCoefficientSeed::engine();
+    for (sig, msg, pubkey) in &items {
+        let sig_bytes = sig.signature.to_bytes();
+        let r_bytes: [u8; 32] = sig_bytes[..32].try_into().expect("schnorr sig is 64 bytes");
+        let s_bytes: [u8; 32] = sig_bytes[32..].try_into().expect("schnorr sig is 64 bytes");
+        let p_bytes = pubkey.serialize();
+
+        let r = lift_x_even_y(&r_bytes).ok_or(BatchVerifyError)?;
+        let p = lift_x_even_y(&p_bytes).ok_or(BatchVerifyError)?;
+        let s = Option::<Scalar>::from(Scalar::from_repr(s_bytes.into())).ok_or(BatchVerifyError)?;
+        let e = challenge_scalar(&r_bytes, &p_bytes, msg.as_ref());
+
+        seed_engine.input(&r_bytes);
+        seed_engine.input(&s_bytes);
+        seed_engine.input(&p_bytes);
+        seed_engine.input(msg.as_ref());
+
+        parsed.push((r, s, p, e));
+    }
+
+    let seed = BatchVerifyCoefficientSeed::from_engine(seed_engine);
+    let mut counter: u64 = 0;
+
+    let mut s_acc = Scalar::ZERO;
+    let mut r_acc = ProjectivePoint::IDENTITY;
+    let mut p_acc = ProjectivePoint::IDENTITY;
+
+    for (i, (r, s, p, e)) in parsed.into_iter().enumerate() {
+        let a = if i == 0 { Scalar::ONE } else { random_nonzero_scalar(&seed, &mut counter) };
+
+        s_acc += a * s;
+        r_acc += ProjectivePoint::from(r) * a;
+        p_acc += ProjectivePoint::from(p) * (a * e);
+    }
+
+    let lhs = ProjectivePoint::GENERATOR * s_acc;
+    let rhs = r_acc + p_acc;
+
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(BatchVerifyError)
+    }
+}
+
+/// Lifts a BIP340 x-only coordinate to the curve point with even Y, as required by the spec.
+fn lift_x_even_y(x_bytes: &[u8; 32]) -> Option<AffinePoint> {
+    Option::from(AffinePoint::decompress(x_bytes.into(), 0.into()))
+}
+
+/// Computes the BIP340 challenge `e = int(tagged_hash("BIP0340/challenge", R || P || m)) mod n`,
+/// reusing the crate's tagged-hash machinery (the same construction `TapSighash`/`TapLeafHash`
+/// are built on) rather than hand-rolling SHA256 tag prefixing.
+fn challenge_scalar(r_x: &[u8; 32], p_x: &[u8; 32], msg: &[u8]) -> Scalar {
+    let mut engine = Bip340Challenge::engine();
+    engine.input(r_x);
+    engine.input(p_x);
+    engine.input(msg);
+    let hash = Bip340Challenge::from_engine(engine);
+    Scalar::reduce_bytes(hash.as_byte_array().into())
+}
+
+/// Draws the `i`-th pseudorandom non-zero scalar from `seed` by tagged-hashing `seed || counter`
+/// and incrementing `counter` on every draw, including rejected ones.
+///
+/// A zero coefficient would drop its signature from the batch equation entirely, defeating the
+/// check, so the draw is rejection-sampled until it lands on a non-zero, in-range scalar.
+fn random_nonzero_scalar(seed: &BatchVerifyCoefficientSeed, counter: &mut u64) -> Scalar {
+    loop {
+        let mut engine = BatchVerifyCoefficient::engine();
+        engine.input(seed.as_byte_array());
+        engine.input(&counter.to_be_bytes());
+        *counter += 1;
+
+        let digest = BatchVerifyCoefficient::from_engine(engine);
+        if let Some(scalar) = Option::<Scalar>::from(Scalar::from_repr((*digest.as_byte_array()).into())) {
+            if scalar != Scalar::ZERO {
+                return scalar;
+            }
+        }
+    }
+}
+
+sha256t_hash_newtype! {
+    /// The tag for [`Bip340Challenge`].
+    pub struct Bip340ChallengeTag = hash_str("BIP0340/challenge");
+
+    /// The BIP340 challenge hash `e = tagged_hash("BIP0340/challenge", R || P || m)`.
+    #[hash_newtype(forward)]
+    pub struct Bip340Challenge(_);
+}
+
+sha256t_hash_newtype! {
+    /// The tag for [`BatchVerifyCoefficientSeed`].
+    pub struct BatchVerifyCoefficientSeedTag = hash_str("bitcoin-rs/taproot-batch-verify-seed");
+
+    /// A hash of every `(R, s, P, m)` in a [`batch_verify`] call, used to seed its pseudorandom
+    /// coefficients.
+    #[hash_newtype(forward)]
+    pub struct BatchVerifyCoefficientSeed(_);
+}
+
+sha256t_hash_newtype! {
+    /// The tag for [`BatchVerifyCoefficient`].
+    pub struct BatchVerifyCoefficientTag = hash_str("bitcoin-rs/taproot-batch-verify-coefficient");
+
+    /// One pseudorandom coefficient drawn from a [`BatchVerifyCoefficientSeed`] and a counter.
+    #[hash_newtype(forward)]
+    pub struct BatchVerifyCoefficient(_);
+}
+
+/// An error indicating that BIP340 batch verification of a group of taproot signatures failed.
+///
+/// The batch check cannot identify which signature was invalid; callers that need to know must
+/// fall back to verifying each signature individually with [`Signature::verify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchVerifyError;
+
+impl fmt::Display for BatchVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BIP340 batch signature verification failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchVerifyError {}
+
+/// The taproot signature slice passed to [`Signature::from_slice`] was neither 64 nor 65 bytes.
+///
+/// This is its own type, rather than a variant of [`SigFromSliceError`], so that code which
+/// already knows a slice has a good length (PSBT and sighash parsing, for example) can check and
+/// propagate a length mismatch on its own, without going through the rest of signature parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidSignatureSizeError(pub usize);
+
+impl fmt::Display for InvalidSignatureSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid taproot signature size: {} (expected 64 or 65)", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSignatureSizeError {}
+
+/// An error parsing a [`taproot::Signature`] out of a slice that is already known to have a
+/// valid length.
 ///
 /// [`taproot::Signature`]: crate::crypto::taproot::Signature
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -120,8 +385,6 @@ pub enum SigFromSliceError {
     SighashType(InvalidSighashTypeError),
     /// A secp256k1 error.
     Secp256k1(CryptoError),
-    /// Invalid taproot signature size
-    InvalidSignatureSize(usize),
 }
 
 internals::impl_from_infallible!(SigFromSliceError);
@@ -133,7 +396,6 @@ impl fmt::Display for SigFromSliceError {
         match *self {
             SighashType(ref e) => write_err!(f, "sighash"; e),
             Secp256k1(ref e) => write_err!(f, "secp256k1"; e),
-            InvalidSignatureSize(sz) => write!(f, "invalid taproot signature size: {}", sz),
         }
     }
 }
@@ -146,7 +408,6 @@ impl std::error::Error for SigFromSliceError {
         match *self {
             Secp256k1(ref e) => Some(e),
             SighashType(ref e) => Some(e),
-            InvalidSignatureSize(_) => None,
         }
     }
 }
@@ -162,3 +423,143 @@ impl From<InvalidSighashTypeError> for SigFromSliceError {
         Self::SighashType(err)
     }
 }
+
+/// An error constructing a [`taproot::Signature`] from a byte slice.
+///
+/// [`taproot::Signature`]: crate::crypto::taproot::Signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FromSliceError {
+    /// The slice was not 64 or 65 bytes, the two valid taproot signature lengths.
+    InvalidSize(InvalidSignatureSizeError),
+    /// The slice had a valid length but failed to parse as a signature.
+    Sig(SigFromSliceError),
+}
+
+impl fmt::Display for FromSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use FromSliceError::*;
+
+        match *self {
+            InvalidSize(ref e) => write_err!(f, "invalid size"; e),
+            Sig(ref e) => write_err!(f, "signature"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromSliceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use FromSliceError::*;
+
+        match *self {
+            InvalidSize(ref e) => Some(e),
+            Sig(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidSignatureSizeError> for FromSliceError {
+    fn from(e: InvalidSignatureSizeError) -> Self {
+        Self::InvalidSize(e)
+    }
+}
+
+impl From<SigFromSliceError> for FromSliceError {
+    fn from(e: SigFromSliceError) -> Self {
+        Self::Sig(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair(byte: u8) -> Keypair {
+        Keypair::from_seckey_bytes([byte; 32]).expect("valid secret key bytes")
+    }
+
+    fn sighash(byte: u8) -> TapSighash {
+        TapSighash::from_byte_array([byte; 32])
+    }
+
+    fn signed_item(seed: u8) -> (Signature, TapSighash, XOnlyPublicKey) {
+        let keypair = keypair(seed);
+        let (pubkey, _parity) = keypair.x_only_public_key();
+        let msg = sighash(seed.wrapping_add(100));
+        let sig = Signature::sign(msg, &keypair, TapSighashType::Default);
+        (sig, msg, pubkey)
+    }
+
+    #[test]
+    fn sign_then_verify_round_trips() {
+        let keypair = keypair(1);
+        let (pubkey, _parity) = keypair.x_only_public_key();
+        let msg = sighash(2);
+
+        let sig = Signature::sign(msg, &keypair, TapSighashType::Default);
+        assert!(sig.verify(msg, &pubkey).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_wrong_message() {
+        let keypair = keypair(1);
+        let (pubkey, _parity) = keypair.x_only_public_key();
+        let sig = Signature::sign(sighash(2), &keypair, TapSighashType::Default);
+
+        assert!(sig.verify(sighash(3), &pubkey).is_err());
+    }
+
+    #[test]
+    fn batch_verify_of_empty_input_is_vacuously_ok() {
+        assert!(batch_verify(Vec::<(Signature, TapSighash, XOnlyPublicKey)>::new()).is_ok());
+    }
+
+    #[test]
+    fn batch_verify_accepts_valid_signatures() {
+        let items: Vec<_> = (1u8..5).map(signed_item).collect();
+
+        assert!(batch_verify(items).is_ok());
+    }
+
+    #[test]
+    fn batch_verify_rejects_a_single_tampered_signature() {
+        let mut items: Vec<_> = (1u8..5).map(signed_item).collect();
+
+        // Flip one byte of one signature's `s` scalar so the batch equation no longer balances.
+        let mut bytes = items[1].0.signature.to_bytes();
+        bytes[40] ^= 0x01;
+        items[1].0.signature =
+            k256::schnorr::Signature::try_from(bytes.as_slice()).expect("still a valid encoding");
+
+        assert!(batch_verify(items).is_err());
+    }
+
+    #[test]
+    fn to_string_from_str_round_trips_default_sighash_type() {
+        let keypair = keypair(1);
+        let sig = Signature::sign(sighash(2), &keypair, TapSighashType::Default);
+
+        let parsed: Signature = sig.to_string().parse().expect("valid hex signature");
+        assert_eq!(parsed, sig);
+    }
+
+    #[test]
+    fn to_string_from_str_round_trips_non_default_sighash_type() {
+        let keypair = keypair(1);
+        let sig = Signature::sign(sighash(2), &keypair, TapSighashType::All);
+
+        let parsed: Signature = sig.to_string().parse().expect("valid hex signature");
+        assert_eq!(parsed, sig);
+    }
+
+    #[test]
+    fn from_str_rejects_invalid_hex() {
+        assert!("not hex".parse::<Signature>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_wrong_length() {
+        assert!("ab".parse::<Signature>().is_err());
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Partially Signed Bitcoin Transactions.
+//!
+//! This module implements the taproot-related PSBT input fields needed to finalize a taproot
+//! input once a sighash has been computed. The data model follows BIP371: a key-spend signature
+//! lives in `tap_key_sig`, and script-spend signatures live in `tap_script_sigs`, keyed by the
+//! leaf public key and the hash of the leaf script that was actually satisfied.
+
+use crate::crypto::taproot;
+use crate::key::{Keypair, XOnlyPublicKey};
+use crate::prelude::*;
+use crate::sighash::{TapLeafHash, TapSighash, TapSighashType};
+
+/// A key-value map for a single PSBT input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    /// The Taproot key spend signature. Required for a taproot key path spend.
+    pub tap_key_sig: Option<taproot::Signature>,
+    /// A map from a taproot script spend's leaf public key and leaf hash to its signature.
+    pub tap_script_sigs: BTreeMap<(XOnlyPublicKey, TapLeafHash), taproot::Signature>,
+}
+
+impl Input {
+    /// Signs `sighash` with `keypair` and inserts the resulting [`taproot::Signature`] into this
+    /// input, choosing `tap_key_sig` or `tap_script_sigs` based on whether `leaf_hash` is given.
+    ///
+    /// This owns the "which field does this signature belong in" decision so callers don't have
+    /// to hand-build a [`taproot::Signature`] and insert it into the right map themselves: pass
+    /// `leaf_hash: None` for a key spend and `Some(leaf_hash)` for the script spend of that leaf.
+    pub fn sign_taproot(
+        &mut self,
+        sighash: TapSighash,
+        keypair: &Keypair,
+        pubkey: XOnlyPublicKey,
+        leaf_hash: Option<TapLeafHash>,
+        sighash_type: TapSighashType,
+    ) {
+        let signature = taproot::Signature::sign(sighash, keypair, sighash_type);
+        match leaf_hash {
+            Some(leaf_hash) => {
+                self.tap_script_sigs.insert((pubkey, leaf_hash), signature);
+            }
+            None => {
+                self.tap_key_sig = Some(signature);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair(byte: u8) -> Keypair {
+        Keypair::from_seckey_bytes([byte; 32]).expect("valid secret key bytes")
+    }
+
+    #[test]
+    fn sign_taproot_without_leaf_hash_sets_tap_key_sig() {
+        let keypair = keypair(1);
+        let (pubkey, _parity) = keypair.x_only_public_key();
+        let sighash = TapSighash::from_byte_array([2; 32]);
+
+        let mut input = Input::default();
+        input.sign_taproot(sighash, &keypair, pubkey, None, TapSighashType::Default);
+
+        assert!(input.tap_key_sig.is_some());
+        assert!(input.tap_script_sigs.is_empty());
+    }
+
+    #[test]
+    fn sign_taproot_with_leaf_hash_keys_tap_script_sigs_by_pubkey_and_leaf_hash() {
+        let keypair = keypair(1);
+        let (pubkey, _parity) = keypair.x_only_public_key();
+        let sighash = TapSighash::from_byte_array([2; 32]);
+        let leaf_hash = TapLeafHash::from_byte_array([3; 32]);
+
+        let mut input = Input::default();
+        input.sign_taproot(sighash, &keypair, pubkey, Some(leaf_hash), TapSighashType::Default);
+
+        assert!(input.tap_key_sig.is_none());
+        assert_eq!(input.tap_script_sigs.len(), 1);
+        assert!(input.tap_script_sigs.contains_key(&(pubkey, leaf_hash)));
+    }
+}